@@ -96,6 +96,25 @@ pub struct WaveGenerator {
     amplitude: f32,
     shape: WaveShape,
     custom_harmonics: Vec<f32>,
+    antialiasing: bool,
+    pulse_width: f32,
+    noise_register: u16,
+    noise_metallic: bool,
+    noise_output: f32,
+}
+
+// PolyBLEP residual used to round off the discontinuity at phase `t` (normalized to
+// the step size `dt`), so naive saw/square edges don't fold back as aliasing.
+fn polyblep(t: f32, dt: f32) -> f32 {
+    if t < dt {
+        let t = t / dt;
+        t + t - t * t - 1.0
+    } else if t > 1.0 - dt {
+        let t = (t - 1.0) / dt;
+        t * t + t + t + 1.0
+    } else {
+        0.0
+    }
 }
 
 #[wasm_bindgen]
@@ -109,9 +128,29 @@ impl WaveGenerator {
             amplitude: 1.0,
             shape: WaveShape::Sine,
             custom_harmonics: vec![1.0, 0.5, 0.25, 0.125], // Default harmonic series
+            antialiasing: false,
+            pulse_width: 0.5,
+            noise_register: 0x7fff, // must never be zero or the LFSR locks up
+            noise_metallic: false,
+            noise_output: -1.0,
         }
     }
-    
+
+    #[wasm_bindgen]
+    pub fn set_antialiasing(&mut self, enabled: bool) {
+        self.antialiasing = enabled;
+    }
+
+    #[wasm_bindgen]
+    pub fn set_pulse_width(&mut self, width: f32) {
+        self.pulse_width = width.max(0.01).min(0.99);
+    }
+
+    #[wasm_bindgen]
+    pub fn set_noise_metallic(&mut self, metallic: bool) {
+        self.noise_metallic = metallic;
+    }
+
     #[wasm_bindgen]
     pub fn set_frequency(&mut self, freq: f32) {
         self.frequency = freq;
@@ -134,10 +173,25 @@ impl WaveGenerator {
     
     #[wasm_bindgen]
     pub fn generate_sample(&mut self) -> f32 {
+        let dt = self.frequency / self.sample_rate;
         let sample = match self.shape {
             WaveShape::Sine => (self.phase * 2.0 * std::f32::consts::PI).sin(),
-            WaveShape::Saw => 2.0 * (self.phase - (self.phase + 0.5).floor()) - 1.0,
-            WaveShape::Square => if self.phase < 0.5 { 1.0 } else { -1.0 },
+            WaveShape::Saw => {
+                let naive = 2.0 * self.phase - 1.0;
+                if self.antialiasing {
+                    naive - polyblep(self.phase, dt)
+                } else {
+                    naive
+                }
+            },
+            WaveShape::Square => {
+                let naive = if self.phase < self.pulse_width { 1.0 } else { -1.0 };
+                if self.antialiasing {
+                    naive + polyblep(self.phase, dt) - polyblep((self.phase + (1.0 - self.pulse_width)) % 1.0, dt)
+                } else {
+                    naive
+                }
+            },
             WaveShape::Triangle => {
                 if self.phase < 0.5 {
                     4.0 * self.phase - 1.0
@@ -145,7 +199,7 @@ impl WaveGenerator {
                     3.0 - 4.0 * self.phase
                 }
             },
-            WaveShape::Noise => js_sys::Math::random() as f32 * 2.0 - 1.0,
+            WaveShape::Noise => self.noise_output,
             WaveShape::Custom => {
                 let mut sample = 0.0;
                 for (i, &harmonic) in self.custom_harmonics.iter().enumerate() {
@@ -159,8 +213,15 @@ impl WaveGenerator {
         self.phase += self.frequency / self.sample_rate;
         if self.phase >= 1.0 {
             self.phase -= 1.0;
+
+            // Advance the LFSR once per waveform cycle so the noise is pitched/tonal
+            // rather than recomputed (and expensive) every sample.
+            let tap_bit = if self.noise_metallic { 6 } else { 1 };
+            let new_bit = (self.noise_register & 0x1) ^ ((self.noise_register >> tap_bit) & 0x1);
+            self.noise_register = (self.noise_register >> 1) | (new_bit << 14);
+            self.noise_output = if self.noise_register & 0x1 == 1 { 1.0 } else { -1.0 };
         }
-        
+
         sample * self.amplitude
     }
     
@@ -391,12 +452,303 @@ impl Filter {
     }
 }
 
+// Simple feedback delay line: writes `input + feedback·delayed`, mixes `delayed` against the dry signal
+#[wasm_bindgen]
+pub struct Delay {
+    buffer: Vec<f32>,
+    write_pos: usize,
+    delay_time: f32,
+    feedback: f32,
+    mix: f32,
+    sample_rate: f32,
+}
+
+#[wasm_bindgen]
+impl Delay {
+    #[wasm_bindgen(constructor)]
+    pub fn new(sample_rate: f32, max_delay_seconds: f32) -> Delay {
+        let buffer_len = ((sample_rate * max_delay_seconds) as usize).max(1);
+        Delay {
+            buffer: vec![0.0; buffer_len],
+            write_pos: 0,
+            delay_time: max_delay_seconds * 0.5,
+            feedback: 0.3,
+            mix: 0.3,
+            sample_rate,
+        }
+    }
+
+    #[wasm_bindgen]
+    pub fn set_delay_time(&mut self, delay_time: f32) {
+        let max_delay = self.buffer.len() as f32 / self.sample_rate;
+        self.delay_time = delay_time.max(0.0).min(max_delay);
+    }
+
+    #[wasm_bindgen]
+    pub fn set_feedback(&mut self, feedback: f32) {
+        self.feedback = feedback.max(0.0).min(0.99);
+    }
+
+    #[wasm_bindgen]
+    pub fn set_mix(&mut self, mix: f32) {
+        self.mix = mix.max(0.0).min(1.0);
+    }
+
+    #[wasm_bindgen]
+    pub fn process(&mut self, input: f32) -> f32 {
+        let delay_samples = (self.delay_time * self.sample_rate) as usize;
+        let read_pos = (self.write_pos + self.buffer.len() - delay_samples.min(self.buffer.len() - 1)) % self.buffer.len();
+        let delayed = self.buffer[read_pos];
+
+        self.buffer[self.write_pos] = input + self.feedback * delayed;
+        self.write_pos = (self.write_pos + 1) % self.buffer.len();
+
+        input * (1.0 - self.mix) + delayed * self.mix
+    }
+
+    #[wasm_bindgen]
+    pub fn process_buffer(&mut self, buffer: &mut [f32]) {
+        for sample in buffer.iter_mut() {
+            *sample = self.process(*sample);
+        }
+    }
+}
+
+// Comb filter: one tap of a Schroeder reverb's parallel delay-with-feedback bank
+struct CombFilter {
+    buffer: Vec<f32>,
+    pos: usize,
+    feedback: f32,
+}
+
+impl CombFilter {
+    fn new(length: usize) -> CombFilter {
+        CombFilter { buffer: vec![0.0; length], pos: 0, feedback: 0.5 }
+    }
+
+    fn process(&mut self, input: f32) -> f32 {
+        let out = self.buffer[self.pos];
+        self.buffer[self.pos] = input + out * self.feedback;
+        self.pos = (self.pos + 1) % self.buffer.len();
+        out
+    }
+}
+
+// All-pass filter: `y = -g·x + x_delayed + g·y_delayed`, used to diffuse the comb output
+struct AllPassFilter {
+    x_buffer: Vec<f32>,
+    y_buffer: Vec<f32>,
+    pos: usize,
+    g: f32,
+}
+
+impl AllPassFilter {
+    fn new(length: usize, g: f32) -> AllPassFilter {
+        AllPassFilter { x_buffer: vec![0.0; length], y_buffer: vec![0.0; length], pos: 0, g }
+    }
+
+    fn process(&mut self, input: f32) -> f32 {
+        let x_delayed = self.x_buffer[self.pos];
+        let y_delayed = self.y_buffer[self.pos];
+        let out = -self.g * input + x_delayed + self.g * y_delayed;
+
+        self.x_buffer[self.pos] = input;
+        self.y_buffer[self.pos] = out;
+        self.pos = (self.pos + 1) % self.x_buffer.len();
+
+        out
+    }
+}
+
+// Schroeder reverb: four parallel combs at staggered lengths, summed and diffused
+// through two short all-pass filters
+#[wasm_bindgen]
+pub struct Reverb {
+    combs: Vec<CombFilter>,
+    allpasses: Vec<AllPassFilter>,
+    mix: f32,
+}
+
+#[wasm_bindgen]
+impl Reverb {
+    #[wasm_bindgen(constructor)]
+    pub fn new(_sample_rate: f32) -> Reverb {
+        Reverb {
+            combs: vec![1557, 1617, 1491, 1422].into_iter().map(CombFilter::new).collect(),
+            allpasses: vec![(225, 0.5), (556, 0.5)].into_iter().map(|(len, g)| AllPassFilter::new(len, g)).collect(),
+            mix: 0.3,
+        }
+    }
+
+    #[wasm_bindgen]
+    pub fn set_room_size(&mut self, room_size: f32) {
+        let feedback = room_size.max(0.0).min(0.99);
+        for comb in &mut self.combs {
+            comb.feedback = feedback;
+        }
+    }
+
+    #[wasm_bindgen]
+    pub fn set_mix(&mut self, mix: f32) {
+        self.mix = mix.max(0.0).min(1.0);
+    }
+
+    #[wasm_bindgen]
+    pub fn process(&mut self, input: f32) -> f32 {
+        let mut wet = self.combs.iter_mut().map(|comb| comb.process(input)).sum::<f32>() / self.combs.len() as f32;
+        for allpass in &mut self.allpasses {
+            wet = allpass.process(wet);
+        }
+
+        input * (1.0 - self.mix) + wet * self.mix
+    }
+
+    #[wasm_bindgen]
+    pub fn process_buffer(&mut self, buffer: &mut [f32]) {
+        for sample in buffer.iter_mut() {
+            *sample = self.process(*sample);
+        }
+    }
+}
+
+// Internal DSP instance backing a plugin's entry in the master effects chain
+enum Effect {
+    Delay(Delay),
+    Reverb(Reverb),
+}
+
+impl Effect {
+    fn process(&mut self, input: f32) -> f32 {
+        match self {
+            Effect::Delay(delay) => delay.process(input),
+            Effect::Reverb(reverb) => reverb.process(input),
+        }
+    }
+
+    // Copies the parameter values from the plugin descriptor into the DSP instance.
+    // The synthesizer calls this with the plugin stored by `add_plugin`, which takes the
+    // plugin by value; it exposes no way to change that stored plugin's parameters later.
+    fn sync_parameters(&mut self, plugin: &AudioPlugin) {
+        match self {
+            Effect::Delay(delay) => {
+                if let Some(v) = plugin.get_parameter("delay_time") { delay.set_delay_time(v as f32); }
+                if let Some(v) = plugin.get_parameter("feedback") { delay.set_feedback(v as f32); }
+                if let Some(v) = plugin.get_parameter("mix") { delay.set_mix(v as f32); }
+            },
+            Effect::Reverb(reverb) => {
+                if let Some(v) = plugin.get_parameter("room_size") { reverb.set_room_size(v as f32); }
+                if let Some(v) = plugin.get_parameter("mix") { reverb.set_mix(v as f32); }
+            },
+        }
+    }
+}
+
+// Shape used by an `Lfo`'s own internal oscillator
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug)]
+pub enum LfoShape {
+    Sine = 0,
+    Triangle = 1,
+    Square = 2,
+}
+
+// Reusable low-frequency oscillator: rate, depth, and a delay before it fades in,
+// used both for pitch modulation (vibrato) and amplitude modulation (tremolo).
+#[wasm_bindgen]
+pub struct Lfo {
+    shape: LfoShape,
+    rate: f32,
+    depth: f32,
+    delay: f32,
+    phase: f32,
+    time: f32,
+    sample_rate: f32,
+}
+
+#[wasm_bindgen]
+impl Lfo {
+    #[wasm_bindgen(constructor)]
+    pub fn new(sample_rate: f32) -> Lfo {
+        Lfo {
+            shape: LfoShape::Sine,
+            rate: 5.0,
+            depth: 0.0,
+            delay: 0.0,
+            phase: 0.0,
+            time: 0.0,
+            sample_rate,
+        }
+    }
+
+    #[wasm_bindgen]
+    pub fn set_shape(&mut self, shape: LfoShape) {
+        self.shape = shape;
+    }
+
+    #[wasm_bindgen]
+    pub fn set_rate(&mut self, rate: f32) {
+        self.rate = rate;
+    }
+
+    #[wasm_bindgen]
+    pub fn set_depth(&mut self, depth: f32) {
+        self.depth = depth;
+    }
+
+    #[wasm_bindgen]
+    pub fn set_delay(&mut self, delay: f32) {
+        self.delay = delay;
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn depth(&self) -> f32 {
+        self.depth
+    }
+
+    #[wasm_bindgen]
+    pub fn reset(&mut self) {
+        self.phase = 0.0;
+        self.time = 0.0;
+    }
+
+    // Returns the depth-scaled oscillator value for this sample, ramped in over `delay`
+    // seconds after note onset, and advances the internal phase.
+    #[wasm_bindgen]
+    pub fn next_value(&mut self) -> f32 {
+        let raw = match self.shape {
+            LfoShape::Sine => (self.phase * 2.0 * std::f32::consts::PI).sin(),
+            LfoShape::Triangle => {
+                if self.phase < 0.5 {
+                    4.0 * self.phase - 1.0
+                } else {
+                    3.0 - 4.0 * self.phase
+                }
+            },
+            LfoShape::Square => if self.phase < 0.5 { 1.0 } else { -1.0 },
+        };
+
+        self.phase += self.rate / self.sample_rate;
+        if self.phase >= 1.0 {
+            self.phase -= 1.0;
+        }
+        self.time += 1.0 / self.sample_rate;
+
+        let onset = if self.delay > 0.0 { (self.time / self.delay).min(1.0) } else { 1.0 };
+        raw * self.depth * onset
+    }
+}
+
 // Voice class for polyphonic synthesis
 #[wasm_bindgen]
 pub struct Voice {
     generator: WaveGenerator,
     envelope: EnvelopeGenerator,
     filter: Filter,
+    vibrato: Lfo,
+    tremolo: Lfo,
+    auto_pan: Lfo,
+    pan: f32,
+    base_frequency: f32,
     note: u8,
     velocity: f32,
     is_active: bool,
@@ -410,56 +762,113 @@ impl Voice {
             generator: WaveGenerator::new(sample_rate),
             envelope: EnvelopeGenerator::new(sample_rate),
             filter: Filter::new(sample_rate),
+            vibrato: Lfo::new(sample_rate),
+            tremolo: Lfo::new(sample_rate),
+            auto_pan: Lfo::new(sample_rate),
+            pan: 0.0,
+            base_frequency: 440.0,
             note: 60,
             velocity: 1.0,
             is_active: false,
         }
     }
-    
+
     #[wasm_bindgen]
     pub fn note_on(&mut self, note: u8, velocity: f32) {
         self.note = note;
         self.velocity = velocity;
         self.is_active = true;
-        
+
         // Convert MIDI note to frequency
-        let frequency = 440.0 * 2.0_f32.powf((note as f32 - 69.0) / 12.0);
-        self.generator.set_frequency(frequency);
+        self.base_frequency = 440.0 * 2.0_f32.powf((note as f32 - 69.0) / 12.0);
+        self.generator.set_frequency(self.base_frequency);
         self.generator.set_amplitude(velocity);
         self.envelope.note_on();
+        self.vibrato.reset();
+        self.tremolo.reset();
     }
-    
+
     #[wasm_bindgen]
     pub fn note_off(&mut self) {
         self.envelope.note_off();
     }
-    
+
     #[wasm_bindgen]
     pub fn generate_sample(&mut self) -> f32 {
         if !self.is_active {
             return 0.0;
         }
-        
+
+        // Pitch LFO (vibrato): depth is in semitones, applied before generating the sample
+        let vibrato_semitones = self.vibrato.next_value();
+        let modulated_freq = self.base_frequency * 2.0_f32.powf(vibrato_semitones / 12.0);
+        self.generator.set_frequency(modulated_freq);
+
         let sample = self.generator.generate_sample();
         let envelope_value = self.envelope.get_value();
-        let filtered_sample = self.filter.process(sample * envelope_value);
-        
+
+        // Amplitude LFO (tremolo): scales the post-envelope sample. The LFO's raw output
+        // is in [-depth, depth]; rescale (not rectify) it into a [1-depth, 1] gain range
+        // so the dip happens once per LFO period instead of twice.
+        let tremolo_value = self.tremolo.next_value();
+        let tremolo_gain = 1.0 - (self.tremolo.depth() - tremolo_value) * 0.5;
+        let filtered_sample = self.filter.process(sample * envelope_value * tremolo_gain);
+
         if !self.envelope.is_active() {
             self.is_active = false;
         }
-        
+
         filtered_sample
     }
-    
+
+    // Generates the next sample and splits it into a constant-power-panned (left, right)
+    // pair, advancing the auto-pan LFO (if any) on top of the static `pan` position.
+    #[wasm_bindgen]
+    pub fn generate_stereo_sample(&mut self) -> Vec<f32> {
+        let sample = self.generate_sample();
+        let pan = (self.pan + self.auto_pan.next_value()).max(-1.0).min(1.0);
+        let theta = (pan + 1.0) * std::f32::consts::PI / 4.0;
+        vec![sample * theta.cos(), sample * theta.sin()]
+    }
+
     #[wasm_bindgen]
     pub fn is_active(&self) -> bool {
         self.is_active
     }
-    
+
+    #[wasm_bindgen]
+    pub fn set_pan(&mut self, pan: f32) {
+        self.pan = pan.max(-1.0).min(1.0);
+    }
+
+    #[wasm_bindgen]
+    pub fn set_auto_pan(&mut self, rate: f32, depth: f32) {
+        self.auto_pan.set_shape(LfoShape::Sine);
+        self.auto_pan.set_rate(rate);
+        self.auto_pan.set_depth(depth.max(0.0).min(1.0));
+        self.auto_pan.set_delay(0.0);
+    }
+
     #[wasm_bindgen]
     pub fn set_wave_shape(&mut self, shape: WaveShape) {
         self.generator.set_wave_shape(shape);
     }
+
+    #[wasm_bindgen]
+    pub fn set_vibrato(&mut self, rate: f32, depth_semitones: f32, delay: f32) {
+        self.vibrato.set_shape(LfoShape::Sine);
+        self.vibrato.set_rate(rate);
+        self.vibrato.set_depth(depth_semitones);
+        self.vibrato.set_delay(delay);
+    }
+
+    #[wasm_bindgen]
+    pub fn set_tremolo(&mut self, rate: f32, depth: f32) {
+        self.tremolo.set_shape(LfoShape::Sine);
+        self.tremolo.set_rate(rate);
+        self.tremolo.set_depth(depth.max(0.0).min(1.0));
+        self.tremolo.set_delay(0.0);
+    }
     
     #[wasm_bindgen]
     pub fn set_envelope(&mut self, attack: f32, decay: f32, sustain: f32, release: f32) {
@@ -474,14 +883,228 @@ impl Voice {
     }
 }
 
+// FM operator: one node in a 4-operator algorithm graph (phase accumulator + envelope)
+#[wasm_bindgen]
+pub struct Operator {
+    sample_rate: f32,
+    phase: f32,
+    ratio: f32,
+    detune_cents: f32,
+    level: f32,
+    feedback: f32,
+    envelope: EnvelopeGenerator,
+    history: [f32; 2],
+}
+
+#[wasm_bindgen]
+impl Operator {
+    #[wasm_bindgen(constructor)]
+    pub fn new(sample_rate: f32) -> Operator {
+        Operator {
+            sample_rate,
+            phase: 0.0,
+            ratio: 1.0,
+            detune_cents: 0.0,
+            level: 1.0,
+            feedback: 0.0,
+            envelope: EnvelopeGenerator::new(sample_rate),
+            history: [0.0, 0.0],
+        }
+    }
+
+    #[wasm_bindgen]
+    pub fn set_ratio(&mut self, ratio: f32) {
+        self.ratio = ratio;
+    }
+
+    #[wasm_bindgen]
+    pub fn set_detune(&mut self, cents: f32) {
+        self.detune_cents = cents;
+    }
+
+    #[wasm_bindgen]
+    pub fn set_level(&mut self, level: f32) {
+        self.level = level;
+    }
+
+    #[wasm_bindgen]
+    pub fn set_feedback(&mut self, feedback: f32) {
+        self.feedback = feedback;
+    }
+
+    #[wasm_bindgen]
+    pub fn set_envelope(&mut self, attack: f32, decay: f32, sustain: f32, release: f32) {
+        self.envelope.set_adsr(attack, decay, sustain, release);
+    }
+
+    #[wasm_bindgen]
+    pub fn note_on(&mut self) {
+        self.phase = 0.0;
+        self.history = [0.0, 0.0];
+        self.envelope.note_on();
+    }
+
+    #[wasm_bindgen]
+    pub fn note_off(&mut self) {
+        self.envelope.note_off();
+    }
+
+    // Advances the phase accumulator and returns `env * level * sin(2π·(phase + modulation + fb))`,
+    // where `modulation` is the summed, scaled output of the operators feeding this one and
+    // `fb` is `feedback` times the mean of this operator's previous two outputs.
+    #[wasm_bindgen]
+    pub fn generate_sample(&mut self, base_freq: f32, modulation: f32) -> f32 {
+        let env = self.envelope.get_value();
+        let feedback_mod = self.feedback * (self.history[0] + self.history[1]) * 0.5;
+        let out = env * self.level * (2.0 * std::f32::consts::PI * (self.phase + modulation + feedback_mod)).sin();
+
+        let detune_hz = base_freq * (2.0_f32.powf(self.detune_cents / 1200.0) - 1.0);
+        self.phase += (base_freq * self.ratio + detune_hz) / self.sample_rate;
+        if self.phase >= 1.0 {
+            self.phase -= 1.0;
+        }
+
+        self.history[1] = self.history[0];
+        self.history[0] = out;
+        out
+    }
+
+    #[wasm_bindgen]
+    pub fn is_active(&self) -> bool {
+        self.envelope.is_active()
+    }
+}
+
+// FM voice: N operators wired together by one of a small table of routing algorithms
+#[wasm_bindgen]
+pub struct FmVoice {
+    operators: Vec<Operator>,
+    algorithm: u8,
+    base_freq: f32,
+    note: u8,
+    velocity: f32,
+    is_active: bool,
+}
+
+#[wasm_bindgen]
+impl FmVoice {
+    #[wasm_bindgen(constructor)]
+    pub fn new(sample_rate: f32) -> FmVoice {
+        FmVoice {
+            operators: (0..4).map(|_| Operator::new(sample_rate)).collect(),
+            algorithm: 0,
+            base_freq: 440.0,
+            note: 60,
+            velocity: 1.0,
+            is_active: false,
+        }
+    }
+
+    #[wasm_bindgen]
+    pub fn set_algorithm(&mut self, algorithm: u8) {
+        self.algorithm = algorithm;
+    }
+
+    #[wasm_bindgen]
+    pub fn set_operator(&mut self, index: usize, ratio: f32, detune_cents: f32, level: f32) {
+        if let Some(operator) = self.operators.get_mut(index) {
+            operator.set_ratio(ratio);
+            operator.set_detune(detune_cents);
+            operator.set_level(level);
+        }
+    }
+
+    #[wasm_bindgen]
+    pub fn set_operator_envelope(&mut self, index: usize, attack: f32, decay: f32, sustain: f32, release: f32) {
+        if let Some(operator) = self.operators.get_mut(index) {
+            operator.set_envelope(attack, decay, sustain, release);
+        }
+    }
+
+    #[wasm_bindgen]
+    pub fn set_feedback(&mut self, feedback: f32) {
+        if let Some(operator) = self.operators.get_mut(0) {
+            operator.set_feedback(feedback);
+        }
+    }
+
+    #[wasm_bindgen]
+    pub fn note_on(&mut self, note: u8, velocity: f32) {
+        self.note = note;
+        self.velocity = velocity;
+        self.is_active = true;
+        self.base_freq = 440.0 * 2.0_f32.powf((note as f32 - 69.0) / 12.0);
+        for operator in &mut self.operators {
+            operator.note_on();
+        }
+    }
+
+    #[wasm_bindgen]
+    pub fn note_off(&mut self) {
+        for operator in &mut self.operators {
+            operator.note_off();
+        }
+    }
+
+    #[wasm_bindgen]
+    pub fn generate_sample(&mut self) -> f32 {
+        if !self.is_active {
+            return 0.0;
+        }
+
+        let base_freq = self.base_freq;
+        let output = match self.algorithm {
+            // Serial chain: 4 -> 3 -> 2 -> 1 (op1 is the carrier)
+            0 => {
+                let op4 = self.operators[3].generate_sample(base_freq, 0.0);
+                let op3 = self.operators[2].generate_sample(base_freq, op4);
+                let op2 = self.operators[1].generate_sample(base_freq, op3);
+                self.operators[0].generate_sample(base_freq, op2)
+            }
+            // Parallel pairs: 2 -> 1 and 4 -> 3, both carriers summed
+            1 => {
+                let op2 = self.operators[1].generate_sample(base_freq, 0.0);
+                let op1 = self.operators[0].generate_sample(base_freq, op2);
+                let op4 = self.operators[3].generate_sample(base_freq, 0.0);
+                let op3 = self.operators[2].generate_sample(base_freq, op4);
+                (op1 + op3) * 0.5
+            }
+            // One modulator, three carriers: 4 modulates 1, 2, and 3
+            _ => {
+                let op4 = self.operators[3].generate_sample(base_freq, 0.0);
+                let op1 = self.operators[0].generate_sample(base_freq, op4);
+                let op2 = self.operators[1].generate_sample(base_freq, op4);
+                let op3 = self.operators[2].generate_sample(base_freq, op4);
+                (op1 + op2 + op3) / 3.0
+            }
+        };
+
+        if !self.operators.iter().any(|op| op.is_active()) {
+            self.is_active = false;
+        }
+
+        output * self.velocity
+    }
+
+    #[wasm_bindgen]
+    pub fn is_active(&self) -> bool {
+        self.is_active
+    }
+}
+
 // Main synthesizer engine
 #[wasm_bindgen]
 pub struct AudioSynthesizer {
     sample_rate: f32,
     voices: Vec<Voice>,
     max_voices: usize,
+    fm_voices: Vec<FmVoice>,
     master_volume: f32,
     plugins: HashMap<String, AudioPlugin>,
+    // Kept as two independent chains (rather than one reused instance) so each stereo
+    // channel gets its own delay/comb-filter state instead of sharing one and aliasing.
+    effects_chain: Vec<(String, Effect)>,
+    effects_chain_right: Vec<(String, Effect)>,
 }
 
 #[wasm_bindgen]
@@ -492,15 +1115,49 @@ impl AudioSynthesizer {
         for _ in 0..max_voices {
             voices.push(Voice::new(sample_rate));
         }
-        
+
         AudioSynthesizer {
             sample_rate,
             voices,
             max_voices,
+            fm_voices: Vec::new(),
             master_volume: 0.8,
             plugins: HashMap::new(),
+            effects_chain: Vec::new(),
+            effects_chain_right: Vec::new(),
+        }
+    }
+
+    #[wasm_bindgen]
+    pub fn set_max_fm_voices(&mut self, max_fm_voices: usize) {
+        self.fm_voices = (0..max_fm_voices).map(|_| FmVoice::new(self.sample_rate)).collect();
+    }
+
+    #[wasm_bindgen]
+    pub fn fm_note_on(&mut self, note: u8, velocity: f32) {
+        let voice_index = self.fm_voices.iter()
+            .position(|v| !v.is_active())
+            .unwrap_or(0);
+
+        if let Some(voice) = self.fm_voices.get_mut(voice_index) {
+            voice.note_on(note, velocity);
         }
     }
+
+    #[wasm_bindgen]
+    pub fn fm_note_off(&mut self, note: u8) {
+        for voice in &mut self.fm_voices {
+            if voice.note == note && voice.is_active {
+                voice.note_off();
+                break;
+            }
+        }
+    }
+
+    #[wasm_bindgen]
+    pub fn get_active_fm_voice_count(&self) -> usize {
+        self.fm_voices.iter().filter(|v| v.is_active()).count()
+    }
     
     #[wasm_bindgen]
     pub fn note_on(&mut self, note: u8, velocity: f32) {
@@ -527,25 +1184,43 @@ impl AudioSynthesizer {
         for voice in &mut self.voices {
             voice.note_off();
         }
+        for voice in &mut self.fm_voices {
+            voice.note_off();
+        }
     }
-    
+
     #[wasm_bindgen]
     pub fn generate_sample(&mut self) -> f32 {
         let mut sample = 0.0;
         let mut active_voices = 0;
-        
+
         for voice in &mut self.voices {
             if voice.is_active() {
                 sample += voice.generate_sample();
                 active_voices += 1;
             }
         }
-        
+
+        for voice in &mut self.fm_voices {
+            if voice.is_active() {
+                sample += voice.generate_sample();
+                active_voices += 1;
+            }
+        }
+
         // Normalize by number of active voices to prevent clipping
         if active_voices > 0 {
             sample = sample / (active_voices as f32).sqrt();
         }
-        
+
+        // Run the summed voices through the ordered master effects chain
+        for (id, effect) in &mut self.effects_chain {
+            if let Some(plugin) = self.plugins.get(id) {
+                effect.sync_parameters(plugin);
+            }
+            sample = effect.process(sample);
+        }
+
         sample * self.master_volume
     }
     
@@ -579,12 +1254,125 @@ impl AudioSynthesizer {
             voice.set_filter(cutoff, resonance, filter_type);
         }
     }
-    
+
+    #[wasm_bindgen]
+    pub fn set_global_vibrato(&mut self, rate: f32, depth_semitones: f32, delay: f32) {
+        for voice in &mut self.voices {
+            voice.set_vibrato(rate, depth_semitones, delay);
+        }
+    }
+
+    #[wasm_bindgen]
+    pub fn set_global_tremolo(&mut self, rate: f32, depth: f32) {
+        for voice in &mut self.voices {
+            voice.set_tremolo(rate, depth);
+        }
+    }
+
+    #[wasm_bindgen]
+    pub fn set_voice_pan(&mut self, voice_index: usize, pan: f32) {
+        if let Some(voice) = self.voices.get_mut(voice_index) {
+            voice.set_pan(pan);
+        }
+    }
+
+    #[wasm_bindgen]
+    pub fn set_global_pan(&mut self, pan: f32) {
+        for voice in &mut self.voices {
+            voice.set_pan(pan);
+        }
+    }
+
+    #[wasm_bindgen]
+    pub fn set_global_auto_pan(&mut self, rate: f32, depth: f32) {
+        for voice in &mut self.voices {
+            voice.set_auto_pan(rate, depth);
+        }
+    }
+
+    // Sums the active voices' stereo pairs, normalizing the same way `generate_sample` does
+    fn sum_stereo_voices(&mut self) -> (f32, f32) {
+        let mut left = 0.0;
+        let mut right = 0.0;
+        let mut active_voices = 0;
+
+        for voice in &mut self.voices {
+            if voice.is_active() {
+                let stereo = voice.generate_stereo_sample();
+                left += stereo[0];
+                right += stereo[1];
+                active_voices += 1;
+            }
+        }
+
+        for voice in &mut self.fm_voices {
+            if voice.is_active() {
+                let sample = voice.generate_sample();
+                left += sample;
+                right += sample;
+                active_voices += 1;
+            }
+        }
+
+        if active_voices > 0 {
+            let norm = (active_voices as f32).sqrt();
+            left /= norm;
+            right /= norm;
+        }
+
+        // Run each channel through its own instance of the master effects chain,
+        // the same way `generate_sample` runs the mono sum through `effects_chain`.
+        for (id, effect) in &mut self.effects_chain {
+            if let Some(plugin) = self.plugins.get(id) {
+                effect.sync_parameters(plugin);
+            }
+            left = effect.process(left);
+        }
+        for (id, effect) in &mut self.effects_chain_right {
+            if let Some(plugin) = self.plugins.get(id) {
+                effect.sync_parameters(plugin);
+            }
+            right = effect.process(right);
+        }
+
+        (left * self.master_volume, right * self.master_volume)
+    }
+
+    // Interleaved L/R output suitable for a two-channel `AudioContext` destination
+    #[wasm_bindgen]
+    pub fn generate_stereo_buffer(&mut self, length: usize) -> Vec<f32> {
+        let mut buffer = Vec::with_capacity(length * 2);
+        for _ in 0..length {
+            let (left, right) = self.sum_stereo_voices();
+            buffer.push(left);
+            buffer.push(right);
+        }
+        buffer
+    }
+
     #[wasm_bindgen]
     pub fn add_plugin(&mut self, plugin: AudioPlugin) {
+        if let Some(mut left) = Self::build_effect(&plugin, self.sample_rate) {
+            left.sync_parameters(&plugin);
+            self.effects_chain.push((plugin.id.clone(), left));
+        }
+
+        if let Some(mut right) = Self::build_effect(&plugin, self.sample_rate) {
+            right.sync_parameters(&plugin);
+            self.effects_chain_right.push((plugin.id.clone(), right));
+        }
+
         self.plugins.insert(plugin.id.clone(), plugin);
     }
-    
+
+    fn build_effect(plugin: &AudioPlugin, sample_rate: f32) -> Option<Effect> {
+        match plugin.plugin_type.as_str() {
+            "delay" => Some(Effect::Delay(Delay::new(sample_rate, 2.0))),
+            "reverb" => Some(Effect::Reverb(Reverb::new(sample_rate))),
+            _ => None,
+        }
+    }
+
     #[wasm_bindgen]
     pub fn get_active_voice_count(&self) -> usize {
         self.voices.iter().filter(|v| v.is_active()).count()
@@ -615,19 +1403,81 @@ pub fn apply_window_function(mut buffer: Vec<f32>, window_type: &str) -> Vec<f32
     buffer
 }
 
+// In-place iterative radix-2 Cooley-Tukey FFT over parallel re/im buffers.
+// `re.len()` must be a power of two; `im` is typically all zeros for real input.
+fn fft_radix2(re: &mut [f32], im: &mut [f32]) {
+    let n = re.len();
+    assert!(n.is_power_of_two(), "FFT size must be a power of two");
+
+    // A single sample has no bit-reversal or butterfly stages to run: reverse_bits'
+    // shift below would be a full-width shift (overflow) for n == 1, and there are
+    // no pairs to combine, so the transform is already complete.
+    if n <= 1 {
+        return;
+    }
+
+    // Bit-reverse the input ordering
+    let bits = n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - bits);
+        if j > i {
+            re.swap(i, j);
+            im.swap(i, j);
+        }
+    }
+
+    // Butterfly stages: block size m doubles each stage, twiddle advances across the half-block
+    let mut m = 2;
+    while m <= n {
+        let half = m / 2;
+        let theta = -2.0 * std::f32::consts::PI / m as f32;
+        for block_start in (0..n).step_by(m) {
+            for k in 0..half {
+                let w_re = (theta * k as f32).cos();
+                let w_im = (theta * k as f32).sin();
+                let a = block_start + k;
+                let b = block_start + k + half;
+
+                let b_re = re[b] * w_re - im[b] * w_im;
+                let b_im = re[b] * w_im + im[b] * w_re;
+
+                re[b] = re[a] - b_re;
+                im[b] = im[a] - b_im;
+                re[a] += b_re;
+                im[a] += b_im;
+            }
+        }
+        m *= 2;
+    }
+}
+
 #[wasm_bindgen]
 pub fn calculate_fft_magnitude(buffer: Vec<f32>) -> Vec<f32> {
-    // Simple magnitude calculation for visualization
-    // In a real implementation, you'd use a proper FFT library
-    let mut magnitudes = Vec::new();
-    let chunk_size = 4;
-    
-    for chunk in buffer.chunks(chunk_size) {
-        let magnitude = chunk.iter().map(|x| x * x).sum::<f32>().sqrt() / chunk.len() as f32;
-        magnitudes.push(magnitude);
+    if buffer.is_empty() {
+        return Vec::new();
     }
-    
-    magnitudes
+
+    // `fft_radix2` requires a power-of-two length; zero-pad rather than trap on
+    // whatever size an `AnalyserNode` (or other caller) happens to hand us.
+    let fft_size = buffer.len().next_power_of_two();
+    let mut re = buffer;
+    re.resize(fft_size, 0.0);
+    let mut im = vec![0.0; fft_size];
+    fft_radix2(&mut re, &mut im);
+
+    re.iter().zip(im.iter())
+        .take(fft_size / 2)
+        .map(|(r, i)| (r * r + i * i).sqrt())
+        .collect()
+}
+
+// Same as `calculate_fft_magnitude` but pads or truncates the input to `fft_size`
+// samples first, so callers can feed an arbitrary-length buffer straight into it.
+#[wasm_bindgen]
+pub fn calculate_fft_magnitude_sized(buffer: Vec<f32>, fft_size: usize) -> Vec<f32> {
+    let mut re = buffer;
+    re.resize(fft_size, 0.0);
+    calculate_fft_magnitude(re)
 }
 
 // Preset management
@@ -643,6 +1493,14 @@ pub struct SynthPreset {
     filter_cutoff: f32,
     filter_resonance: f32,
     filter_type: u8,
+    vibrato_rate: f32,
+    vibrato_depth_semitones: f32,
+    vibrato_delay: f32,
+    tremolo_rate: f32,
+    tremolo_depth: f32,
+    pan: f32,
+    auto_pan_rate: f32,
+    auto_pan_depth: f32,
 }
 
 #[wasm_bindgen]
@@ -659,9 +1517,17 @@ impl SynthPreset {
             filter_cutoff: 1000.0,
             filter_resonance: 1.0,
             filter_type: 0, // LowPass
+            vibrato_rate: 5.0,
+            vibrato_depth_semitones: 0.0,
+            vibrato_delay: 0.0,
+            tremolo_rate: 5.0,
+            tremolo_depth: 0.0,
+            pan: 0.0,
+            auto_pan_rate: 0.2,
+            auto_pan_depth: 0.0,
         }
     }
-    
+
     #[wasm_bindgen]
     pub fn apply_to_synth(&self, synth: &mut AudioSynthesizer) {
         let wave_shape = match self.wave_shape {
@@ -683,6 +1549,10 @@ impl SynthPreset {
         synth.set_global_wave_shape(wave_shape);
         synth.set_global_envelope(self.attack, self.decay, self.sustain, self.release);
         synth.set_global_filter(self.filter_cutoff, self.filter_resonance, filter_type);
+        synth.set_global_vibrato(self.vibrato_rate, self.vibrato_depth_semitones, self.vibrato_delay);
+        synth.set_global_tremolo(self.tremolo_rate, self.tremolo_depth);
+        synth.set_global_pan(self.pan);
+        synth.set_global_auto_pan(self.auto_pan_rate, self.auto_pan_depth);
     }
     
     #[wasm_bindgen(getter)]